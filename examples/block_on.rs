@@ -0,0 +1,113 @@
+// The `my_ready!` example leans on `#[tokio::main]` to drive `MyFut`, but the whole point of
+// manual polling is that you don't need a runtime to understand it. This example provides a
+// tiny `block_on` that drives any `Future` to completion using nothing but `std`.
+//
+// How a real executor works, in miniature:
+// - A `Future` is polled with a `Context`, which carries a `Waker`.
+// - When the future can't make progress it returns `Poll::Pending` and keeps the `Waker`.
+// - Later, something (I/O, a timer, another thread) calls `waker.wake()` to say "poll me again".
+// - The executor blocks in the meantime instead of spinning the CPU.
+//
+// We implement that "block in the meantime" step with `std::thread::park`/`unpark`: the waker
+// holds the executor thread's handle, and `wake` just unparks it.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    thread::Thread,
+};
+
+// A `Waker` is built from a type-erased data pointer plus a vtable of `unsafe fn`s. We store a
+// cloned `Thread` handle behind an `Arc` so the refcount tracks how many wakers are alive, and
+// the vtable functions turn that pointer back into the `Arc<Thread>` to operate on it.
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+// `clone` bumps the `Arc<Thread>` refcount and hands back a fresh `RawWaker` over the same data.
+unsafe fn clone(data: *const ()) -> RawWaker {
+    let arc = Arc::from_raw(data as *const Thread);
+    // `into_raw` the original so we don't drop it, and `into_raw` the clone for the new waker.
+    let _ = Arc::into_raw(Arc::clone(&arc));
+    let _ = Arc::into_raw(arc);
+    RawWaker::new(data, &VTABLE)
+}
+
+// `wake` consumes the waker: reconstruct the `Arc`, unpark the thread, then let the `Arc` drop.
+unsafe fn wake(data: *const ()) {
+    let arc = Arc::from_raw(data as *const Thread);
+    arc.unpark();
+}
+
+// `wake_by_ref` unparks without consuming the waker, so we must not drop our `Arc`.
+unsafe fn wake_by_ref(data: *const ()) {
+    let arc = Arc::from_raw(data as *const Thread);
+    arc.unpark();
+    let _ = Arc::into_raw(arc);
+}
+
+// `drop` releases one refcount on the `Arc<Thread>`.
+unsafe fn drop_waker(data: *const ()) {
+    drop(Arc::from_raw(data as *const Thread));
+}
+
+fn thread_waker() -> Waker {
+    let arc = Arc::new(std::thread::current());
+    let data = Arc::into_raw(arc) as *const ();
+    // Safety: `data` came from `Arc::into_raw` and `VTABLE`'s functions treat it as exactly that.
+    unsafe { Waker::from_raw(RawWaker::new(data, &VTABLE)) }
+}
+
+/// Drives `fut` to completion on the current thread using only `std`, returning its output.
+///
+/// The future is pinned to the stack and polled in a loop: on `Poll::Ready` we return the value,
+/// and on `Poll::Pending` we `park` the thread until the waker `unpark`s us and polls again.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    // Pin the future on the stack so it never moves while we poll it.
+    let mut fut = fut;
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+    let waker = thread_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(v) => return v,
+            // No progress yet: sleep until the waker unparks us, then poll again.
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+// A minimal leaf future, same two-phase shape as `MyFut` in `my_ready.rs`: it reports `Pending`
+// once (waking itself so the executor re-polls) and `Ready` the second time.
+struct MyFut {
+    polled: bool,
+    v: usize,
+}
+
+impl MyFut {
+    fn new(v: usize) -> Self {
+        Self { polled: false, v }
+    }
+}
+
+impl Future for MyFut {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.polled {
+            Poll::Ready(self.v)
+        } else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn main() {
+    // No `#[tokio::main]`, no runtime dependency — just `std`.
+    let result = block_on(MyFut::new(42));
+    println!("Final result: {}", result);
+}
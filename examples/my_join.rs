@@ -0,0 +1,162 @@
+// Extending the manual-polling theme of `MyFut`/`my_ready!`, this example builds `my_join!`: a
+// macro that drives several heterogeneous futures to completion concurrently inside a single
+// task, then hands back a tuple of their outputs.
+//
+// "Concurrently in one task" means: on every `poll` we give each unfinished sub-future a chance
+// to make progress, instead of `.await`-ing them one after another. The combinator only reports
+// `Poll::Ready` once every sub-future has finished.
+
+use std::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// Each slot holds one sub-future in one of three states:
+// - `Pending(F)` : not finished yet, still needs polling.
+// - `Done(T)`    : finished, value parked here until we assemble the output tuple.
+// - `Gone`       : value has been moved out into the tuple; never polled again.
+enum MaybeDone<F: Future> {
+    Pending(F),
+    Done(F::Output),
+    Gone,
+}
+
+impl<F: Future> MaybeDone<F> {
+    // Poll this slot once. Returns `true` when the slot is (now or already) `Done`.
+    //
+    // Safety: the caller guarantees `self` is pinned (the slots live inside the pinned `Join`
+    // struct), so projecting a pin to the inner future is sound as long as we never move it.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // We never move the inner future out of the slot while polling, only the finished value
+        // afterwards, so `get_unchecked_mut` here is sound.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            MaybeDone::Pending(f) => {
+                let f = unsafe { Pin::new_unchecked(f) };
+                match f.poll(cx) {
+                    Poll::Ready(v) => {
+                        *this = MaybeDone::Done(v);
+                        true
+                    }
+                    Poll::Pending => false,
+                }
+            }
+            // Already finished — do not re-poll a completed future.
+            MaybeDone::Done(_) => true,
+            MaybeDone::Gone => true,
+        }
+    }
+
+    // Move the finished value out, leaving the slot `Gone`. Panics if not yet `Done`.
+    fn take(self: Pin<&mut Self>) -> F::Output {
+        let this = unsafe { self.get_unchecked_mut() };
+        match mem::replace(this, MaybeDone::Gone) {
+            MaybeDone::Done(v) => v,
+            _ => panic!("take() called on a slot that was not Done"),
+        }
+    }
+}
+
+// The backing future produced by `my_join!`. One generic slot per input future.
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Give every still-pending slot a chance to advance on this poll. Each sub-future
+        // registers its own waker through the shared `Context`, so returning `Pending` when any
+        // slot is unfinished is enough — whichever slot is waiting will wake us.
+        let mut all_done = true;
+        all_done &= unsafe { Pin::new_unchecked(&mut this.a) }.poll(cx);
+        all_done &= unsafe { Pin::new_unchecked(&mut this.b) }.poll(cx);
+
+        if all_done {
+            let a = unsafe { Pin::new_unchecked(&mut this.a) }.take();
+            let b = unsafe { Pin::new_unchecked(&mut this.b) }.take();
+            Poll::Ready((a, b))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Concurrently drive several futures to completion and collect their outputs into a tuple.
+///
+/// The two-future case is the primitive `Join`. The three- and four-future cases nest `Join`s —
+/// the outer poll still drives every leaf on each call, so they remain concurrent — and an `async`
+/// block destructures the nested result back into a flat tuple. The macro accepts two to four
+/// futures; other counts do not match any arm. Usage: `let (x, y) = my_join!(fa, fb).await;`
+#[macro_export]
+macro_rules! my_join {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::join_two($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        async {
+            let ((a, b), c) = $crate::join_two($crate::join_two($a, $b), $c).await;
+            (a, b, c)
+        }
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        async {
+            let (((a, b), c), d) =
+                $crate::join_two($crate::join_two($crate::join_two($a, $b), $c), $d).await;
+            (a, b, c, d)
+        }
+    };
+}
+
+/// Build a `Join` of exactly two futures — the primitive every `my_join!` arm nests.
+pub fn join_two<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: MaybeDone::Pending(a),
+        b: MaybeDone::Pending(b),
+    }
+}
+
+// A two-phase leaf future like `MyFut`, parameterized so we can join heterogeneous outputs.
+struct Once<T> {
+    polled: bool,
+    v: Option<T>,
+}
+
+impl<T> Once<T> {
+    fn new(v: T) -> Self {
+        Self {
+            polled: false,
+            v: Some(v),
+        }
+    }
+}
+
+impl<T: Unpin> Future for Once<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.polled {
+            Poll::Ready(self.v.take().expect("Once polled after completion"))
+        } else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Heterogeneous outputs: a usize and a &str, finished in one joined task.
+    let (a, b) = my_join!(Once::new(42usize), Once::new("hello")).await;
+    println!("joined two: {} {}", a, b);
+
+    let (x, y, z) = my_join!(Once::new(1u8), Once::new(2u8), Once::new(3u8)).await;
+    println!("joined three: {} {} {}", x, y, z);
+}
@@ -0,0 +1,55 @@
+// `my_pending!()` is a cooperative yield point for use inside `async` blocks: it suspends the
+// task exactly once and then resumes. That's handy for letting other tasks run on a single-
+// threaded executor, and for exercising the `block_on` executor in `block_on.rs` — a yield is the
+// simplest thing that forces a real `Pending -> wake -> Ready` round trip.
+//
+// It mirrors the two-poll behavior already shown by `MyFut` in `my_ready.rs`, but packaged as a
+// reusable `pending_once()` future behind the macro.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A future that returns `Poll::Pending` on its first poll (after scheduling itself to be polled
+/// again) and `Poll::Ready(())` on the second — a single yield back to the executor.
+pub struct PendingOnce {
+    is_ready: bool,
+}
+
+impl Future for PendingOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.is_ready {
+            Poll::Ready(())
+        } else {
+            self.is_ready = true;
+            // Schedule ourselves so the executor re-polls us on the next turn.
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Construct a one-shot yield future. Usually used through `my_pending!()`.
+pub fn pending_once() -> PendingOnce {
+    PendingOnce { is_ready: false }
+}
+
+/// Yield control back to the executor exactly once: `my_pending!().await` resumes on the next poll.
+#[macro_export]
+macro_rules! my_pending {
+    () => {
+        $crate::pending_once().await
+    };
+}
+
+#[tokio::main]
+async fn main() {
+    println!("before yield");
+    // Suspend once, then resume.
+    my_pending!();
+    println!("after yield");
+}
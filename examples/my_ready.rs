@@ -95,7 +95,11 @@ async fn main() {
 fn poll_fut(cx: &mut Context<'_>) -> Poll<usize> {
     let mut fut = MyFut::new(42);
     let fut = Pin::new(&mut fut);
-    my_ready!(fut.poll(cx))
+    // `my_ready!` now yields the bare value `v` (or returns `Poll::Pending` early), so we wrap it
+    // back up in `Poll::Ready` here — exactly how you'd use the stabilized `ready!` inside a
+    // hand-written `poll`.
+    let v = my_ready!(fut.poll(cx));
+    Poll::Ready(v)
 }
 
 // 3, Custom Future Implementation
@@ -138,16 +142,34 @@ impl Future for MyFut {
 // 5, Macro Definition
 // This macro simplifies the polling of futures.
 // It checks if the future is ready.
-// If it is ready, it returns Poll::Ready with the value.
-// If it is not ready, it returns Poll::Pending, allowing the caller to handle it appropriately.
-// Usage: my_ready!(fut.poll(cx))
-// This macro is useful for writing cleaner async code, especially when manually polling futures.
-// This mimics the behavior of the try_ready! or ready! macro in older async versionsâ€”it's for writing manual poll implementations cleanly.
+// If it is ready, it evaluates to the bare value `v` so the rest of the `poll` function can keep
+// using it (this is what the stabilized `core::task::ready!` does).
+// If it is not ready, it returns Poll::Pending from the enclosing function, short-circuiting.
+// Usage: let v = my_ready!(fut.poll(cx));
+// This is the idiom for writing manual poll implementations cleanly.
 #[macro_export]
 macro_rules! my_ready {
     ($expr:expr) => {
         match $expr {
-            std::task::Poll::Ready(v) => std::task::Poll::Ready(v),
+            std::task::Poll::Ready(v) => v,
+            std::task::Poll::Pending => return std::task::Poll::Pending,
+        }
+    };
+}
+
+// 6, Fallible variant
+// For futures that poll to `Poll<Result<T, E>>`, `my_try_ready!` combines `my_ready!` with `?`:
+// - `Poll::Ready(Ok(t))`  -> evaluates to the bare `t`
+// - `Poll::Ready(Err(e))` -> returns `Poll::Ready(Err(e.into()))` from the enclosing `poll`
+// - `Poll::Pending`       -> returns `Poll::Pending`
+// This is the classic `try_ready!` pattern for manual poll implementations over fallible
+// streams/futures. `e.into()` lets the error be converted into the `poll`'s declared error type.
+#[macro_export]
+macro_rules! my_try_ready {
+    ($expr:expr) => {
+        match $expr {
+            std::task::Poll::Ready(Ok(t)) => t,
+            std::task::Poll::Ready(Err(e)) => return std::task::Poll::Ready(Err(e.into())),
             std::task::Poll::Pending => return std::task::Poll::Pending,
         }
     };
@@ -0,0 +1,106 @@
+// Complementing `my_join!`, this example builds `my_select!`: a racing primitive. Where `join`
+// waits for *every* future, `select` resolves as soon as *any one* future is ready, drops the
+// rest, and tells you which branch won.
+//
+// The backing `Any` future stores a homogeneous list of input futures. On each `poll` it loops
+// over them, pinning and polling each; the first `Poll::Ready(v)` wins and we return
+// `Poll::Ready((index, v))`. Dropping the `Any` future drops the remaining, un-finished futures.
+//
+// To keep polling fair — so an always-ready early branch can't starve later ones — we rotate the
+// starting index every poll (round-robin).
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// The backing future for `my_select!`. Holds each input future plus the next index to start
+// polling from, so successive polls rotate who goes first.
+pub struct Any<F> {
+    futures: Vec<F>,
+    start: usize,
+}
+
+impl<F: Future> Future for Any<F> {
+    // The winning branch's index alongside its output.
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let len = this.futures.len();
+
+        // Visit every branch once, but begin at `start` and wrap around for fairness.
+        for offset in 0..len {
+            let i = (this.start + offset) % len;
+            // Safety: the futures live in the `Vec` owned by the pinned `Any`; we never move them
+            // out while polling, so re-pinning in place is sound.
+            let fut = unsafe { Pin::new_unchecked(&mut this.futures[i]) };
+            if let Poll::Ready(v) = fut.poll(cx) {
+                return Poll::Ready((i, v));
+            }
+        }
+
+        // Advance the rotation for next time and report that nobody is ready yet. Each polled
+        // future has registered its waker via `cx`, so we'll be re-polled when one makes progress.
+        this.start = (this.start + 1) % len.max(1);
+        Poll::Pending
+    }
+}
+
+/// Race several same-typed futures; resolves to `(index, output)` of whichever finishes first.
+///
+/// Usage: `let (winner, value) = my_select!(fa, fb, fc).await;`
+#[macro_export]
+macro_rules! my_select {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::select_all(vec![$($fut),+])
+    };
+}
+
+/// Build an `Any` future that races every future in `futures`.
+pub fn select_all<F: Future>(futures: Vec<F>) -> Any<F> {
+    Any { futures, start: 0 }
+}
+
+// A leaf future that becomes ready only after being polled `ticks` times, so different branches
+// finish on different polls and the race has a clear winner.
+struct Ticker {
+    remaining: usize,
+    id: usize,
+}
+
+impl Ticker {
+    fn new(ticks: usize, id: usize) -> Self {
+        Self {
+            remaining: ticks,
+            id,
+        }
+    }
+}
+
+impl Future for Ticker {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.remaining == 0 {
+            Poll::Ready(self.id)
+        } else {
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Branch 1 needs only one tick, so it wins the race.
+    let (index, value) = my_select!(
+        Ticker::new(3, 10),
+        Ticker::new(1, 11),
+        Ticker::new(5, 12),
+    )
+    .await;
+    println!("branch {} won with value {}", index, value);
+}
@@ -4,20 +4,20 @@ fn main() -> Result<()> {
     // let v = my_vec![1, 2, 3];
     // let v: Vec<i32> = my_vec![];
     // let v = vec![1, 2, 3];
-    let v: Vec<i32> = my_vec![
-        "1".parse()?,
-        "2".parse()?,
-        "3".parse()?,
-        "4".parse()?,
-        "5".parse()?,
-        "6".parse()? // what does .parse()? do?
-                     // It converts a string slice to an integer, returning a Result type.
-                     // so, what the result of "6".parse()? looks like?
-                     // It will be Ok(6) if the string is a valid integer, or an error if it is not.
-                     // so, the v has a vec of OK(1), OK(2),...OK(6)
-                     // the compiler will automatically convert the Vec<Result<i32, _>> to Vec<i32> if all are Ok
-    ];
-    println!("{:?}", v); // Output: [1, 2, 3]
+    //
+    // NOTE: `my_vec!` does NOT unwrap results. If you wrote `my_vec!["1".parse()?, ...]` the `?`
+    // runs *before* the macro sees the element, so it is the surrounding function's `?` doing the
+    // unwrapping — the macro only ever builds a `Vec` of whatever values it is handed. There is no
+    // magic `Vec<Result<i32, _>> -> Vec<i32>` conversion; see `try_vec!` below for that behavior.
+    let v: Vec<i32> = my_vec![1, 2, 3, 4, 5, 6];
+    println!("{:?}", v); // Output: [1, 2, 3, 4, 5, 6]
+
+    // `try_vec!` takes elements that each evaluate to a `Result`, yields the first `Err` if there
+    // is one, and otherwise yields `Ok(Vec<T>)` of the unwrapped values. Note there is no `?` on
+    // the individual elements here — `try_vec!` itself performs the propagation.
+    let parsed: Vec<i32> = try_vec!["1".parse(), "2".parse(), "3".parse()]?;
+    println!("{:?}", parsed); // Output: [1, 2, 3]
+
     Ok(())
 }
 
@@ -42,6 +42,25 @@ macro_rules! my_vec {
     };
 }
 
+// try_vec! = try_vec!["1".parse(), "2".parse()] // Result<Vec<i32>, E>
+// Each element evaluates to a `Result<T, E>`; every element is evaluated, then the first `Err` is
+// returned, and otherwise the unwrapped values are collected into a `Vec`. This is the
+// `Vec<Result<T, E>> -> Vec<T>` conversion that `my_vec!` does not do (see the note in `main`).
+#[macro_export]
+macro_rules! try_vec {
+    () => {
+        ::std::result::Result::Ok(::std::vec::Vec::new())
+    };
+    ($($x:expr),+ $(,)?) => {
+        // Collect the `Result` elements into a `Result<Vec<_>, _>`: `collect` stops at the first
+        // `Err` and otherwise yields `Ok` of all the unwrapped values. This is a single expression
+        // of type `Result<Vec<T>, E>`, so it does not hijack the surrounding function's `?`.
+        [$($x),+]
+            .into_iter()
+            .collect::<::std::result::Result<::std::vec::Vec<_>, _>>()
+    };
+}
+
 // why does  <[_]>::into_vec(Box::new([$($x),*])) equals to the above commented code?
 // The expression `<[_]>::into_vec(Box::new([$($x),*]))` creates a boxed slice from the elements and then converts it into a `Vec`.
 // This is a more concise way to create a `Vec` from a list of expressions.
@@ -0,0 +1,73 @@
+// `my_ready.rs` hand-writes a whole `MyFut` struct with a `polled` flag just to demonstrate a
+// two-phase poll. That boilerplate — declare a struct, hold some state, `impl Future` — is
+// exactly what `core::future::poll_fn` removes: you hand it a closure that *is* the `poll` body.
+//
+// Because the closure only ever receives `&mut Context` (it never borrows the future itself),
+// `PollFn` carries no self-references and is therefore `Unpin`. That lets `poll` reach the stored
+// closure through `self.get_mut()` without any `unsafe` pin projection.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A future that delegates its `poll` to a stored closure.
+pub struct PollFn<F> {
+    f: F,
+}
+
+// No pinned data inside, so `PollFn` can move freely.
+impl<F> Unpin for PollFn<F> {}
+
+/// Wrap a closure into a `Future`. Each `poll` simply calls the closure with the current
+/// `Context`, so you can write a throwaway future inline instead of declaring a struct.
+///
+/// ```ignore
+/// let fut = poll_fn(|cx| { cx.waker().wake_by_ref(); Poll::Ready(42) });
+/// ```
+pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    PollFn { f }
+}
+
+impl<T, F> Future for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // `PollFn: Unpin`, so we can take a plain `&mut self` out of the pin and call the closure.
+        (self.get_mut().f)(cx)
+    }
+}
+
+/// Convenience wrapper so the closure reads like the body of a `poll`: `my_poll_fn!(|cx| { .. })`.
+#[macro_export]
+macro_rules! my_poll_fn {
+    ($f:expr) => {
+        $crate::poll_fn($f)
+    };
+}
+
+#[tokio::main]
+async fn main() {
+    // A one-shot future with no struct in sight: `Pending` the first time (waking itself so the
+    // executor comes back), `Ready` the second time.
+    let mut polled = false;
+    let value = my_poll_fn!(move |cx: &mut Context<'_>| {
+        if polled {
+            Poll::Ready(42)
+        } else {
+            polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await;
+
+    println!("poll_fn future resolved to {}", value);
+}
@@ -0,0 +1,117 @@
+// Every future so far (`MyFut`, `Once`, `Ticker`) is synthetic: it wakes *itself* and is "ready"
+// on the next poll. A real leaf future becomes ready because something *external* happened. This
+// example builds `TimerFuture`: it completes after a `Duration`, driven by a background thread
+// that sleeps and then calls the stored `Waker` — a genuine `Pending -> wake -> Ready` cycle.
+//
+// We drive it with a tiny `std`-only `block_on` (see `block_on.rs` for the fully-commented
+// version) so the whole demo runs without a runtime.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    thread::Thread,
+    time::Duration,
+};
+
+// Shared state between the future and its timer thread.
+struct State {
+    completed: bool,
+    // The latest waker handed to us by `poll`. Stored so the timer thread can wake the task, and
+    // refreshed on every poll so it stays valid even if the task migrates between executors.
+    waker: Option<Waker>,
+}
+
+/// A future that resolves to `()` once `dur` has elapsed.
+pub struct TimerFuture {
+    state: Arc<Mutex<State>>,
+}
+
+impl TimerFuture {
+    /// Create a timer that completes after `dur`, spawning the background thread immediately.
+    pub fn new(dur: Duration) -> Self {
+        let state = Arc::new(Mutex::new(State {
+            completed: false,
+            waker: None,
+        }));
+
+        let thread_state = Arc::clone(&state);
+        std::thread::spawn(move || {
+            std::thread::sleep(dur);
+            let mut state = thread_state.lock().unwrap();
+            state.completed = true;
+            // If the task already parked a waker, wake it so the executor re-polls us.
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if state.completed {
+            Poll::Ready(())
+        } else {
+            // Replace any stale waker with the current one before parking.
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// --- minimal std-only executor (see block_on.rs) ---------------------------------------------
+
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+unsafe fn clone(data: *const ()) -> RawWaker {
+    let arc = Arc::from_raw(data as *const Thread);
+    let _ = Arc::into_raw(Arc::clone(&arc));
+    let _ = Arc::into_raw(arc);
+    RawWaker::new(data, &VTABLE)
+}
+
+unsafe fn wake(data: *const ()) {
+    Arc::from_raw(data as *const Thread).unpark();
+}
+
+unsafe fn wake_by_ref(data: *const ()) {
+    let arc = Arc::from_raw(data as *const Thread);
+    arc.unpark();
+    let _ = Arc::into_raw(arc);
+}
+
+unsafe fn drop_waker(data: *const ()) {
+    drop(Arc::from_raw(data as *const Thread));
+}
+
+fn thread_waker() -> Waker {
+    let data = Arc::into_raw(Arc::new(std::thread::current())) as *const ();
+    unsafe { Waker::from_raw(RawWaker::new(data, &VTABLE)) }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = fut;
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+    let waker = thread_waker();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(v) => return v,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+fn main() {
+    println!("waiting...");
+    // The executor thread parks on `Poll::Pending` and is woken by the timer thread ~1s later.
+    block_on(TimerFuture::new(Duration::from_secs(1)));
+    println!("done after ~1s");
+}